@@ -0,0 +1,306 @@
+use {
+    crate::{context::ScillaContext, misc::helpers::with_compute_budget},
+    anyhow::Context,
+    futures::future::join_all,
+    solana_keypair::{Keypair, Signature, Signer},
+    solana_message::Message,
+    solana_system_interface::instruction::transfer,
+    solana_transaction::Transaction,
+    solana_transaction_status_client_types::TransactionConfirmationStatus,
+    std::{
+        io::Write,
+        path::PathBuf,
+        time::{Duration, Instant},
+    },
+};
+
+const FUND_LAMPORTS: u64 = 5_000_000; // enough for a handful of transfers + fees
+const STATUS_POLL_BATCH: usize = 100;
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const STATUS_POLL_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct BenchArgs {
+    pub tx_per_run: usize,
+    pub runs: usize,
+    pub run_interval: Duration,
+    pub metrics_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+pub struct RunMetrics {
+    pub sent: usize,
+    pub confirmed: usize,
+    pub failed: usize,
+    pub timed_out: usize,
+    pub wall_clock: Duration,
+    pub latencies: Vec<Duration>,
+}
+
+impl RunMetrics {
+    pub fn tps(&self) -> f64 {
+        if self.wall_clock.is_zero() {
+            return 0.0;
+        }
+        self.confirmed as f64 / self.wall_clock.as_secs_f64()
+    }
+
+    pub fn percentile(&self, p: f64) -> Duration {
+        if self.latencies.is_empty() {
+            return Duration::ZERO;
+        }
+        let mut sorted = self.latencies.clone();
+        sorted.sort();
+        let idx = ((sorted.len() as f64 - 1.0) * p).round() as usize;
+        sorted[idx]
+    }
+}
+
+pub async fn run_bench(ctx: &ScillaContext, args: &BenchArgs) -> anyhow::Result<Vec<RunMetrics>> {
+    let ephemeral: Vec<Keypair> = (0..args.tx_per_run).map(|_| Keypair::new()).collect();
+    fund_ephemeral_keypairs(ctx, &ephemeral).await?;
+
+    let mut reports = Vec::with_capacity(args.runs);
+
+    for run in 0..args.runs {
+        let metrics = run_once(ctx, &ephemeral).await?;
+        print_run_summary(ctx, run, &metrics);
+        if let Some(path) = &args.metrics_file {
+            append_csv_row(path, run, &metrics)?;
+        }
+        reports.push(metrics);
+
+        if run + 1 < args.runs {
+            tokio::time::sleep(args.run_interval).await;
+        }
+    }
+
+    Ok(reports)
+}
+
+async fn fund_ephemeral_keypairs(ctx: &ScillaContext, ephemeral: &[Keypair]) -> anyhow::Result<()> {
+    for chunk in ephemeral.chunks(8) {
+        let recent_blockhash = ctx.rpc().get_latest_blockhash().await?;
+        let instructions: Vec<_> = chunk
+            .iter()
+            .map(|kp| transfer(&ctx.pubkey(), &kp.pubkey(), FUND_LAMPORTS))
+            .collect();
+        let instructions = with_compute_budget(&instructions, ctx.compute_budget());
+        let message = Message::new(&instructions, Some(ctx.pubkey()));
+        let mut tx = Transaction::new_unsigned(message);
+        tx.try_sign(&[ctx.payer()], recent_blockhash)?;
+        ctx.rpc()
+            .send_and_confirm_transaction(&tx)
+            .await
+            .context("failed to fund bench keypairs")?;
+    }
+    Ok(())
+}
+
+async fn run_once(ctx: &ScillaContext, ephemeral: &[Keypair]) -> anyhow::Result<RunMetrics> {
+    let recent_blockhash = ctx.rpc().get_latest_blockhash().await?;
+    let start = Instant::now();
+
+    let payer_pubkey = ctx.pubkey();
+    let compute_budget = ctx.compute_budget();
+    let sends = ephemeral.iter().map(|kp| {
+        let rpc = ctx.rpc();
+        async move {
+            let ix = transfer(&kp.pubkey(), &payer_pubkey, 1);
+            let instructions = with_compute_budget(&[ix], compute_budget);
+            let message = Message::new(&instructions, Some(kp.pubkey()));
+            let mut tx = Transaction::new_unsigned(message);
+            if tx.try_sign(&[kp], recent_blockhash).is_err() {
+                return None;
+            }
+
+            let submitted_at = Instant::now();
+            rpc.send_transaction(&tx)
+                .await
+                .ok()
+                .map(|signature| (signature, submitted_at))
+        }
+    });
+
+    let submissions: Vec<_> = join_all(sends).await.into_iter().flatten().collect();
+    let sent = ephemeral.len();
+    let failed_to_submit = sent - submissions.len();
+
+    let mut metrics = RunMetrics {
+        sent,
+        failed: failed_to_submit,
+        ..Default::default()
+    };
+
+    let outcomes = poll_signature_statuses(ctx, &submissions).await?;
+    for outcome in outcomes {
+        match outcome {
+            SignatureOutcome::Confirmed(latency) => {
+                metrics.confirmed += 1;
+                metrics.latencies.push(latency);
+            }
+            SignatureOutcome::Failed => metrics.failed += 1,
+            SignatureOutcome::TimedOut => metrics.timed_out += 1,
+        }
+    }
+
+    // Measure against the submission/confirmation window rather than the full poll loop
+    // (which runs up to STATUS_POLL_TIMEOUT), so tps() reflects landing throughput instead
+    // of being dominated by how long the last straggler took to poll.
+    metrics.wall_clock = metrics
+        .latencies
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or_else(|| start.elapsed());
+    Ok(metrics)
+}
+
+#[derive(Clone)]
+enum SignatureOutcome {
+    Confirmed(Duration),
+    Failed,
+    TimedOut,
+}
+
+async fn poll_signature_statuses(
+    ctx: &ScillaContext,
+    submissions: &[(Signature, Instant)],
+) -> anyhow::Result<Vec<SignatureOutcome>> {
+    let mut outcomes = vec![None; submissions.len()];
+    let deadline = Instant::now() + STATUS_POLL_TIMEOUT;
+
+    while Instant::now() < deadline && outcomes.iter().any(Option::is_none) {
+        for (batch_start, chunk) in submissions.chunks(STATUS_POLL_BATCH).enumerate() {
+            let offset = batch_start * STATUS_POLL_BATCH;
+            let signatures: Vec<_> = chunk.iter().map(|(sig, _)| *sig).collect();
+            let statuses = ctx.rpc().get_signature_statuses(&signatures).await?.value;
+
+            for (i, status) in statuses.into_iter().enumerate() {
+                let idx = offset + i;
+                if outcomes[idx].is_some() {
+                    continue;
+                }
+                if let Some(status) = status {
+                    let (_, submitted_at) = submissions[idx];
+                    let landed = matches!(
+                        status.confirmation_status,
+                        Some(
+                            TransactionConfirmationStatus::Confirmed
+                                | TransactionConfirmationStatus::Finalized
+                        )
+                    );
+                    outcomes[idx] = if status.err.is_some() {
+                        Some(SignatureOutcome::Failed)
+                    } else if landed {
+                        // Latency is measured at poll time, not at the moment the cluster
+                        // actually reached this commitment level, so it's biased up to one
+                        // `STATUS_POLL_INTERVAL` late.
+                        Some(SignatureOutcome::Confirmed(submitted_at.elapsed()))
+                    } else {
+                        None
+                    };
+                }
+            }
+        }
+
+        if outcomes.iter().any(Option::is_none) {
+            tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+        }
+    }
+
+    Ok(outcomes
+        .into_iter()
+        .map(|o| o.unwrap_or(SignatureOutcome::TimedOut))
+        .collect())
+}
+
+fn print_run_summary(ctx: &ScillaContext, run: usize, metrics: &RunMetrics) {
+    ctx.display(format!(
+        "run {}: sent={} confirmed={} failed={} timed_out={} tps={:.1} p50={:?} p90={:?} p99={:?}",
+        run + 1,
+        metrics.sent,
+        metrics.confirmed,
+        metrics.failed,
+        metrics.timed_out,
+        metrics.tps(),
+        metrics.percentile(0.50),
+        metrics.percentile(0.90),
+        metrics.percentile(0.99),
+    ));
+}
+
+fn append_csv_row(path: &PathBuf, run: usize, metrics: &RunMetrics) -> anyhow::Result<()> {
+    let file_exists = path.exists();
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("failed to open metrics file {}", path.display()))?;
+
+    if !file_exists {
+        writeln!(
+            file,
+            "run,sent,confirmed,failed,timed_out,tps,p50_ms,p90_ms,p99_ms"
+        )?;
+    }
+
+    writeln!(
+        file,
+        "{},{},{},{},{},{:.2},{},{},{}",
+        run + 1,
+        metrics.sent,
+        metrics.confirmed,
+        metrics.failed,
+        metrics.timed_out,
+        metrics.tps(),
+        metrics.percentile(0.50).as_millis(),
+        metrics.percentile(0.90).as_millis(),
+        metrics.percentile(0.99).as_millis(),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_empty_latencies() {
+        let metrics = RunMetrics::default();
+        assert_eq!(metrics.percentile(0.50), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_percentile_picks_sorted_rank() {
+        let metrics = RunMetrics {
+            latencies: vec![
+                Duration::from_millis(100),
+                Duration::from_millis(300),
+                Duration::from_millis(200),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(metrics.percentile(0.0), Duration::from_millis(100));
+        assert_eq!(metrics.percentile(1.0), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_tps_zero_wall_clock() {
+        let metrics = RunMetrics {
+            confirmed: 10,
+            ..Default::default()
+        };
+        assert_eq!(metrics.tps(), 0.0);
+    }
+
+    #[test]
+    fn test_tps_divides_confirmed_by_wall_clock() {
+        let metrics = RunMetrics {
+            confirmed: 20,
+            wall_clock: Duration::from_secs(2),
+            ..Default::default()
+        };
+        assert_eq!(metrics.tps(), 10.0);
+    }
+}
@@ -0,0 +1,167 @@
+use {
+    crate::{
+        bench::{self, BenchArgs},
+        context::ScillaContext,
+        error::ScillaResult,
+        misc::helpers::{
+            airdrop_to_balance, build_and_send_tx, fetch_lookup_table_accounts, lamports_to_sol,
+            with_compute_budget, ComputeBudget, SolAmount,
+        },
+        multisig,
+    },
+    solana_pubkey::Pubkey,
+    solana_system_interface::instruction::transfer,
+    std::path::PathBuf,
+};
+
+pub enum CommandExec<T> {
+    Process(T),
+    GoBack,
+    Exit,
+}
+
+pub enum Command {
+    Transfer {
+        to: Pubkey,
+        lamports: u64,
+        lookup_tables: Vec<Pubkey>,
+    },
+    SetComputeBudget(ComputeBudget),
+    Bench(BenchArgs),
+    ProposeTransfer {
+        to: Pubkey,
+        lamports: u64,
+        proposal_out: PathBuf,
+    },
+    SignProposal {
+        proposal_path: PathBuf,
+        signature_out: PathBuf,
+    },
+    AssembleProposal {
+        proposal_path: PathBuf,
+        signature_paths: Vec<PathBuf>,
+    },
+    Airdrop {
+        target: Option<Pubkey>,
+        desired: SolAmount,
+    },
+    Exit,
+}
+
+impl Command {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            Command::Transfer {
+                to,
+                lamports,
+                lookup_tables,
+            } => {
+                let ix = transfer(&ctx.pubkey(), to, *lamports);
+                let lookup_tables = fetch_lookup_table_accounts(ctx, lookup_tables).await?;
+                let signature =
+                    build_and_send_tx(ctx, &[ix], &[ctx.payer()], &lookup_tables).await?;
+                ctx.display(format!("Transfer landed: {signature}"));
+                Ok(CommandExec::Process(()))
+            }
+            Command::SetComputeBudget(compute_budget) => {
+                ctx.set_compute_budget(*compute_budget);
+                ctx.display("Compute budget updated for this session");
+                Ok(CommandExec::Process(()))
+            }
+            Command::Bench(args) => {
+                bench::run_bench(ctx, args).await?;
+                Ok(CommandExec::Process(()))
+            }
+            Command::ProposeTransfer {
+                to,
+                lamports,
+                proposal_out,
+            } => {
+                let ix = transfer(&ctx.pubkey(), to, *lamports);
+                let instructions = with_compute_budget(&[ix], ctx.compute_budget());
+                let recent_blockhash = ctx.rpc().get_latest_blockhash().await?;
+                let proposal = multisig::UnsignedProposal::new(
+                    &instructions,
+                    &ctx.pubkey(),
+                    recent_blockhash,
+                )?;
+                proposal.write_to_file(proposal_out)?;
+                ctx.display(format!(
+                    "Wrote unsigned proposal to {}",
+                    proposal_out.display()
+                ));
+                Ok(CommandExec::Process(()))
+            }
+            Command::SignProposal {
+                proposal_path,
+                signature_out,
+            } => {
+                let proposal = multisig::UnsignedProposal::read_from_file(proposal_path)?;
+                ctx.display(multisig::describe_proposal(&proposal.message()?));
+                let signature = multisig::sign_proposal(&proposal, ctx.payer())?;
+                multisig::write_signature_file(signature_out, &ctx.pubkey(), &signature)?;
+                ctx.display(format!(
+                    "Wrote detached signature to {}",
+                    signature_out.display()
+                ));
+                Ok(CommandExec::Process(()))
+            }
+            Command::AssembleProposal {
+                proposal_path,
+                signature_paths,
+            } => {
+                let proposal = multisig::UnsignedProposal::read_from_file(proposal_path)?;
+                let collected = signature_paths
+                    .iter()
+                    .map(|path| multisig::read_signature_file(path))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let tx = multisig::assemble_transaction(&proposal, &collected)?;
+                let signature = multisig::submit_assembled(ctx, &tx).await?;
+                ctx.display(format!("Assembled transaction landed: {signature}"));
+                Ok(CommandExec::Process(()))
+            }
+            Command::Airdrop { target, desired } => {
+                let target = target.unwrap_or_else(|| ctx.pubkey());
+                let outcome = airdrop_to_balance(ctx, target, *desired).await?;
+                ctx.display(format!(
+                    "Airdropped {target}: {:.4} SOL -> {:.4} SOL",
+                    lamports_to_sol(outcome.before_lamports),
+                    lamports_to_sol(outcome.after_lamports),
+                ));
+                Ok(CommandExec::Process(()))
+            }
+            Command::Exit => Ok(CommandExec::Exit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{Cluster, ScillaConfig},
+        io::ScriptedIo,
+        prompt::prompt_for_command,
+    };
+    use solana_keypair::{EncodableKey, Keypair};
+
+    #[tokio::test]
+    async fn test_scripted_io_drives_pipeline_to_exit() {
+        let keypair_path =
+            std::env::temp_dir().join(format!("scilla-test-keypair-{}.json", std::process::id()));
+        Keypair::new().write_to_file(&keypair_path).unwrap();
+
+        let config = ScillaConfig {
+            cluster: Cluster::Localnet,
+            keypair_path: keypair_path.clone(),
+        };
+        let io = ScriptedIo::new(["Exit".to_string()]);
+        let ctx = ScillaContext::from_config_with_io(config, Box::new(io)).unwrap();
+
+        let command = prompt_for_command(&ctx).unwrap();
+        let exec = command.process_command(&ctx).await.unwrap();
+        assert!(matches!(exec, CommandExec::Exit));
+
+        std::fs::remove_file(&keypair_path).ok();
+    }
+}
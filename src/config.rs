@@ -0,0 +1,50 @@
+use {
+    anyhow::{anyhow, Context},
+    serde::Deserialize,
+    std::path::PathBuf,
+};
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Cluster {
+    Mainnet,
+    Testnet,
+    Devnet,
+    Localnet,
+}
+
+impl Cluster {
+    pub fn url(&self) -> &'static str {
+        match self {
+            Cluster::Mainnet => "https://api.mainnet-beta.solana.com",
+            Cluster::Testnet => "https://api.testnet.solana.com",
+            Cluster::Devnet => "https://api.devnet.solana.com",
+            Cluster::Localnet => "http://127.0.0.1:8899",
+        }
+    }
+
+    pub fn is_mainnet(&self) -> bool {
+        matches!(self, Cluster::Mainnet)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScillaConfig {
+    pub cluster: Cluster,
+    pub keypair_path: PathBuf,
+}
+
+impl ScillaConfig {
+    pub fn load() -> anyhow::Result<Self> {
+        let path = Self::config_path()?;
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read config at {}", path.display()))?;
+        let config: ScillaConfig = toml::from_str(&contents)?;
+        Ok(config)
+    }
+
+    fn config_path() -> anyhow::Result<PathBuf> {
+        let home = dirs::home_dir().ok_or_else(|| anyhow!("could not resolve home directory"))?;
+        Ok(home.join(".config").join("scilla").join("config.toml"))
+    }
+}
@@ -0,0 +1 @@
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
@@ -0,0 +1,80 @@
+use {
+    crate::{
+        config::ScillaConfig,
+        io::{Io, TerminalIo},
+        misc::helpers::{read_keypair_from_path, ComputeBudget},
+    },
+    anyhow::Context,
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_keypair::{Keypair, Signer},
+    solana_pubkey::Pubkey,
+    std::cell::RefCell,
+};
+
+pub struct ScillaContext {
+    config: ScillaConfig,
+    rpc: RpcClient,
+    payer: Keypair,
+    io: RefCell<Box<dyn Io>>,
+    compute_budget: RefCell<ComputeBudget>,
+}
+
+impl ScillaContext {
+    pub fn from_config(config: ScillaConfig) -> anyhow::Result<Self> {
+        Self::from_config_with_io(config, Box::new(TerminalIo))
+    }
+
+    pub fn from_config_with_io(config: ScillaConfig, io: Box<dyn Io>) -> anyhow::Result<Self> {
+        let payer =
+            read_keypair_from_path(&config.keypair_path).context("failed to load payer keypair")?;
+        let rpc = RpcClient::new(config.cluster.url().to_string());
+
+        Ok(Self {
+            config,
+            rpc,
+            payer,
+            io: RefCell::new(io),
+            compute_budget: RefCell::new(ComputeBudget::default()),
+        })
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    pub fn payer(&self) -> &Keypair {
+        &self.payer
+    }
+
+    pub fn config(&self) -> &ScillaConfig {
+        &self.config
+    }
+
+    pub fn compute_budget(&self) -> ComputeBudget {
+        *self.compute_budget.borrow()
+    }
+
+    pub fn set_compute_budget(&self, compute_budget: ComputeBudget) {
+        *self.compute_budget.borrow_mut() = compute_budget;
+    }
+
+    pub fn read_line(&self, prompt: &str) -> anyhow::Result<String> {
+        self.io.borrow_mut().read_line(prompt)
+    }
+
+    pub fn prompt_select(&self, prompt: &str, items: &[&str]) -> anyhow::Result<usize> {
+        self.io.borrow_mut().prompt_select(prompt, items)
+    }
+
+    pub fn display(&self, msg: impl AsRef<str>) {
+        self.io.borrow_mut().display(msg.as_ref());
+    }
+
+    pub fn display_error(&self, msg: impl AsRef<str>) {
+        self.io.borrow_mut().display_error(msg.as_ref());
+    }
+}
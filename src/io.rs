@@ -0,0 +1,135 @@
+use {
+    anyhow::anyhow,
+    console::style,
+    dialoguer::{theme::ColorfulTheme, Input, Select},
+    std::collections::VecDeque,
+};
+
+// Abstracts interactive terminal prompts and output so the command pipeline can run
+// headless (scripted input, captured output) instead of being hardwired to stdin/stdout.
+pub trait Io: Send {
+    fn read_line(&mut self, prompt: &str) -> anyhow::Result<String>;
+    fn prompt_select(&mut self, prompt: &str, items: &[&str]) -> anyhow::Result<usize>;
+    fn display(&mut self, msg: &str);
+    fn display_error(&mut self, msg: &str);
+}
+
+pub struct TerminalIo;
+
+impl Io for TerminalIo {
+    fn read_line(&mut self, prompt: &str) -> anyhow::Result<String> {
+        let line = Input::<String>::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .allow_empty(true)
+            .interact_text()?;
+        Ok(line)
+    }
+
+    fn prompt_select(&mut self, prompt: &str, items: &[&str]) -> anyhow::Result<usize> {
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .items(items)
+            .default(0)
+            .interact()?;
+        Ok(selection)
+    }
+
+    fn display(&mut self, msg: &str) {
+        println!("{} {}", style("✔").green().bold(), msg);
+    }
+
+    fn display_error(&mut self, msg: &str) {
+        eprintln!("{} {}", style("✘").red().bold(), msg);
+    }
+}
+
+// Drives the command pipeline from a fixed sequence of inputs and captures output into
+// buffers instead of the terminal, so a sequence of commands from a file (or a test) can
+// be asserted on without a real TTY.
+#[derive(Default)]
+pub struct ScriptedIo {
+    inputs: VecDeque<String>,
+    pub output: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+impl ScriptedIo {
+    pub fn new(inputs: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            inputs: inputs.into_iter().collect(),
+            output: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(Self::new(contents.lines().map(str::to_owned)))
+    }
+
+    fn next_input(&mut self) -> anyhow::Result<String> {
+        self.inputs
+            .pop_front()
+            .ok_or_else(|| anyhow!("ran out of scripted input"))
+    }
+}
+
+impl Io for ScriptedIo {
+    fn read_line(&mut self, _prompt: &str) -> anyhow::Result<String> {
+        self.next_input()
+    }
+
+    fn prompt_select(&mut self, _prompt: &str, items: &[&str]) -> anyhow::Result<usize> {
+        let chosen = self.next_input()?;
+        items
+            .iter()
+            .position(|item| *item == chosen)
+            .ok_or_else(|| anyhow!("scripted selection '{chosen}' is not one of the offered items"))
+    }
+
+    fn display(&mut self, msg: &str) {
+        self.output.push(msg.to_owned());
+    }
+
+    fn display_error(&mut self, msg: &str) {
+        self.errors.push(msg.to_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scripted_io_round_trip() {
+        let mut io = ScriptedIo::new(["0.5".to_string(), "Transfer".to_string()]);
+
+        assert_eq!(io.read_line("Amount (SOL)").unwrap(), "0.5");
+        assert_eq!(
+            io.prompt_select("What would you like to do?", &["Transfer", "Exit"])
+                .unwrap(),
+            0
+        );
+
+        io.display("Transfer landed: abc123");
+        io.display_error("RPC request failed");
+
+        assert_eq!(io.output, vec!["Transfer landed: abc123".to_string()]);
+        assert_eq!(io.errors, vec!["RPC request failed".to_string()]);
+    }
+
+    #[test]
+    fn test_scripted_io_rejects_unknown_selection() {
+        let mut io = ScriptedIo::new(["Nonexistent".to_string()]);
+        let err = io
+            .prompt_select("What would you like to do?", &["Transfer", "Exit"])
+            .unwrap_err();
+        assert!(err.to_string().contains("Nonexistent"));
+    }
+
+    #[test]
+    fn test_scripted_io_errors_when_input_exhausted() {
+        let mut io = ScriptedIo::new(Vec::<String>::new());
+        assert!(io.read_line("Recipient pubkey").is_err());
+    }
+}
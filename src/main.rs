@@ -1,19 +1,23 @@
 use {
     crate::{
         commands::CommandExec, config::ScillaConfig, context::ScillaContext, error::ScillaResult,
-        prompt::prompt_for_command,
+        io::ScriptedIo, prompt::prompt_for_command,
     },
+    anyhow::anyhow,
     console::style,
+    std::path::PathBuf,
 };
 
+pub mod bench;
 pub mod commands;
 pub mod config;
 pub mod constants;
 pub mod context;
 pub mod error;
+pub mod io;
 pub mod misc;
+pub mod multisig;
 pub mod prompt;
-pub mod ui;
 
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> ScillaResult<()> {
@@ -25,10 +29,16 @@ async fn main() -> ScillaResult<()> {
     );
 
     let config = ScillaConfig::load()?;
-    let ctx = ScillaContext::from_config(config)?;
+    let ctx = match script_path_from_args(std::env::args())? {
+        Some(script_path) => ScillaContext::from_config_with_io(
+            config,
+            Box::new(ScriptedIo::from_file(script_path)?),
+        )?,
+        None => ScillaContext::from_config(config)?,
+    };
 
     loop {
-        let command = prompt_for_command()?;
+        let command = prompt_for_command(&ctx)?;
 
         let res = command.process_command(&ctx).await?;
 
@@ -41,3 +51,48 @@ async fn main() -> ScillaResult<()> {
 
     Ok(CommandExec::Exit)
 }
+
+// Parses a `--script <file>` flag so Scilla can be driven headlessly (via `ScriptedIo`)
+// instead of prompting an interactive terminal, e.g. for CI or repeatable demos.
+fn script_path_from_args(args: impl Iterator<Item = String>) -> anyhow::Result<Option<PathBuf>> {
+    let mut args = args.skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--script" {
+            let path = args
+                .next()
+                .ok_or_else(|| anyhow!("--script requires a file path"))?;
+            return Ok(Some(PathBuf::from(path)));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_path_from_args_none_by_default() {
+        let args = ["scilla".to_string()];
+        assert!(script_path_from_args(args.into_iter()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_script_path_from_args_parses_flag() {
+        let args = [
+            "scilla".to_string(),
+            "--script".to_string(),
+            "demo.txt".to_string(),
+        ];
+        assert_eq!(
+            script_path_from_args(args.into_iter()).unwrap(),
+            Some(PathBuf::from("demo.txt"))
+        );
+    }
+
+    #[test]
+    fn test_script_path_from_args_missing_value() {
+        let args = ["scilla".to_string(), "--script".to_string()];
+        assert!(script_path_from_args(args.into_iter()).is_err());
+    }
+}
@@ -1,10 +1,14 @@
 use {
-    crate::{ScillaContext, constants::LAMPORTS_PER_SOL},
+    crate::{constants::LAMPORTS_PER_SOL, context::ScillaContext},
     anyhow::{anyhow, bail},
+    solana_address_lookup_table_interface::state::AddressLookupTable,
+    solana_compute_budget_interface::ComputeBudgetInstruction,
     solana_instruction::Instruction,
     solana_keypair::{EncodableKey, Keypair, Signature, Signer},
-    solana_message::Message,
-    solana_transaction::Transaction,
+    solana_message::{v0, AddressLookupTableAccount, Message, VersionedMessage},
+    solana_pubkey::Pubkey,
+    solana_transaction::{versioned::VersionedTransaction, Transaction},
+    solana_transaction_status_client_types::TransactionConfirmationStatus,
     std::{path::Path, str::FromStr},
 };
 
@@ -91,21 +95,189 @@ pub fn read_keypair_from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Keypair
         .map_err(|e| anyhow!("Failed to read keypair from {}: {}", path.display(), e))
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct PriorityFee(u64);
+
+impl PriorityFee {
+    pub fn micro_lamports_per_cu(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for PriorityFee {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fee = match trim_and_parse::<u64>(s, "priority fee")? {
+            Some(val) => val,
+            None => return Ok(PriorityFee(0)),
+        };
+        Ok(PriorityFee(fee))
+    }
+}
+
+pub fn parse_compute_unit_limit(s: &str) -> anyhow::Result<Option<u32>> {
+    trim_and_parse::<u32>(s, "compute unit limit")
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ComputeBudget {
+    pub unit_limit: Option<u32>,
+    pub priority_fee: Option<PriorityFee>,
+}
+
+impl ComputeBudget {
+    fn is_empty(&self) -> bool {
+        self.unit_limit.is_none() && self.priority_fee.is_none()
+    }
+
+    fn instructions(&self) -> Vec<Instruction> {
+        let mut instructions = Vec::with_capacity(2);
+        if let Some(unit_limit) = self.unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(unit_limit));
+        }
+        if let Some(priority_fee) = self.priority_fee {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+                priority_fee.micro_lamports_per_cu(),
+            ));
+        }
+        instructions
+    }
+}
+
+// Prepends `compute_budget`'s instructions (if any) to `instructions`, so every
+// transaction builder in the crate can apply the session's compute budget the same way.
+pub fn with_compute_budget(
+    instructions: &[Instruction],
+    compute_budget: ComputeBudget,
+) -> Vec<Instruction> {
+    if compute_budget.is_empty() {
+        return instructions.to_vec();
+    }
+    let mut with_budget = compute_budget.instructions();
+    with_budget.extend_from_slice(instructions);
+    with_budget
+}
+
+// Legacy path when `lookup_tables` is empty, otherwise compiles a v0 message against the
+// supplied ALTs and sends a VersionedTransaction. `ctx`'s compute budget is prepended to
+// the instruction list when set so callers can attach a priority fee / unit limit.
 pub async fn build_and_send_tx(
     ctx: &ScillaContext,
-    instruction: &[Instruction],
+    instructions: &[Instruction],
     signers: &[&dyn Signer],
+    lookup_tables: &[AddressLookupTableAccount],
 ) -> anyhow::Result<Signature> {
+    let instructions = with_compute_budget(instructions, ctx.compute_budget());
+    let instructions = instructions.as_slice();
+
     let recent_blockhash = ctx.rpc().get_latest_blockhash().await?;
-    let message = Message::new(instruction, Some(ctx.pubkey()));
-    let mut tx = Transaction::new_unsigned(message);
-    tx.try_sign(&signers.to_vec(), recent_blockhash)?;
+
+    if lookup_tables.is_empty() {
+        let message = Message::new(instructions, Some(ctx.pubkey()));
+        let mut tx = Transaction::new_unsigned(message);
+        tx.try_sign(&signers.to_vec(), recent_blockhash)?;
+
+        let signature = ctx.rpc().send_and_confirm_transaction(&tx).await?;
+        return Ok(signature);
+    }
+
+    let message =
+        v0::Message::try_compile(&ctx.pubkey(), instructions, lookup_tables, recent_blockhash)?;
+    let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &signers.to_vec())?;
 
     let signature = ctx.rpc().send_and_confirm_transaction(&tx).await?;
 
     Ok(signature)
 }
 
+const AIRDROP_CONFIRM_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+const AIRDROP_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+pub struct AirdropOutcome {
+    pub before_lamports: u64,
+    pub after_lamports: u64,
+}
+
+// Tops `target` up to `desired` SOL by airdropping the shortfall. Devnet/testnet only;
+// mainnet validators reject `request_airdrop` outright, so we refuse before even asking.
+pub async fn airdrop_to_balance(
+    ctx: &ScillaContext,
+    target: Pubkey,
+    desired: SolAmount,
+) -> anyhow::Result<AirdropOutcome> {
+    if ctx.config().cluster.is_mainnet() {
+        bail!("Airdrops are not available on mainnet");
+    }
+
+    let before_lamports = ctx.rpc().get_balance(&target).await?;
+    let desired_lamports = desired.to_lamports();
+
+    if before_lamports >= desired_lamports {
+        return Ok(AirdropOutcome {
+            before_lamports,
+            after_lamports: before_lamports,
+        });
+    }
+
+    let shortfall = desired_lamports - before_lamports;
+    let signature = ctx.rpc().request_airdrop(&target, shortfall).await?;
+
+    let deadline = std::time::Instant::now() + AIRDROP_CONFIRM_TIMEOUT;
+    loop {
+        let statuses = ctx.rpc().get_signature_statuses(&[signature]).await?.value;
+        if let Some(Some(status)) = statuses.into_iter().next() {
+            if status.err.is_some() {
+                bail!("Airdrop transaction failed: {:?}", status.err);
+            }
+            let landed = matches!(
+                status.confirmation_status,
+                Some(
+                    TransactionConfirmationStatus::Confirmed
+                        | TransactionConfirmationStatus::Finalized
+                )
+            );
+            if landed {
+                break;
+            }
+        }
+
+        if std::time::Instant::now() >= deadline {
+            bail!(
+                "Airdrop did not confirm within {:?}",
+                AIRDROP_CONFIRM_TIMEOUT
+            );
+        }
+        tokio::time::sleep(AIRDROP_POLL_INTERVAL).await;
+    }
+
+    let after_lamports = ctx.rpc().get_balance(&target).await?;
+    Ok(AirdropOutcome {
+        before_lamports,
+        after_lamports,
+    })
+}
+
+pub async fn fetch_lookup_table_accounts(
+    ctx: &ScillaContext,
+    addresses: &[Pubkey],
+) -> anyhow::Result<Vec<AddressLookupTableAccount>> {
+    let mut accounts = Vec::with_capacity(addresses.len());
+
+    for &address in addresses {
+        let account = ctx.rpc().get_account(&address).await?;
+        let table = AddressLookupTable::deserialize(&account.data)
+            .map_err(|e| anyhow!("Failed to deserialize lookup table {}: {}", address, e))?;
+
+        accounts.push(AddressLookupTableAccount {
+            key: address,
+            addresses: table.addresses.to_vec(),
+        });
+    }
+
+    Ok(accounts)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +287,29 @@ mod tests {
         assert_eq!(lamports_to_sol(1_000_000_000), 1.0);
     }
 
+    #[test]
+    fn test_priority_fee_rejects_fractional_input() {
+        assert!(PriorityFee::from_str("0.7").is_err());
+    }
+
+    #[test]
+    fn test_priority_fee_parses_whole_number() {
+        assert_eq!(
+            PriorityFee::from_str("1000")
+                .unwrap()
+                .micro_lamports_per_cu(),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_priority_fee_defaults_to_zero_when_blank() {
+        assert_eq!(
+            PriorityFee::from_str("").unwrap().micro_lamports_per_cu(),
+            0
+        );
+    }
+
     #[test]
     fn test_lamports_to_sol_max_u64() {
         // u64::MAX lamports should not panic or overflow
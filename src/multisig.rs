@@ -0,0 +1,200 @@
+use {
+    crate::context::ScillaContext,
+    anyhow::{anyhow, bail, Context},
+    base64::{engine::general_purpose::STANDARD, Engine as _},
+    solana_hash::Hash,
+    solana_instruction::Instruction,
+    solana_keypair::{Keypair, Signature, Signer},
+    solana_message::Message,
+    solana_pubkey::Pubkey,
+    solana_transaction::Transaction,
+    std::{path::Path, str::FromStr},
+};
+
+// An unsigned `Message` (fee payer + recent blockhash already baked in) serialized so it
+// can be handed to other signers out-of-band for the offline / multisig signing workflow.
+pub struct UnsignedProposal {
+    message_bytes: Vec<u8>,
+}
+
+impl UnsignedProposal {
+    pub fn new(
+        instructions: &[Instruction],
+        fee_payer: &Pubkey,
+        recent_blockhash: Hash,
+    ) -> anyhow::Result<Self> {
+        let message = Message::new_with_blockhash(instructions, Some(fee_payer), &recent_blockhash);
+        let message_bytes = bincode::serialize(&message)?;
+        Ok(Self { message_bytes })
+    }
+
+    pub fn message(&self) -> anyhow::Result<Message> {
+        bincode::deserialize(&self.message_bytes).context("failed to decode proposal message")
+    }
+
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(&self.message_bytes)
+    }
+
+    pub fn from_base64(encoded: &str) -> anyhow::Result<Self> {
+        let message_bytes = STANDARD
+            .decode(encoded.trim())
+            .context("proposal is not valid base64")?;
+        // Round-trip through `Message` so a corrupt or unrelated blob is rejected up front.
+        bincode::deserialize::<Message>(&message_bytes)
+            .context("proposal is not a valid message")?;
+        Ok(Self { message_bytes })
+    }
+
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, self.to_base64())
+            .with_context(|| format!("failed to write proposal to {}", path.display()))
+    }
+
+    pub fn read_from_file(path: &Path) -> anyhow::Result<Self> {
+        let encoded = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read proposal from {}", path.display()))?;
+        Self::from_base64(&encoded)
+    }
+}
+
+pub struct CollectedSignature {
+    pub signer: Pubkey,
+    pub signature: Signature,
+}
+
+// Human-readable summary of a proposal's fee payer, blockhash, and instructions, so a
+// signer can review what they're about to sign instead of blindly trusting the blob.
+pub fn describe_proposal(message: &Message) -> String {
+    let fee_payer = message.account_keys.first().copied().unwrap_or_default();
+    let mut summary = format!(
+        "Fee payer: {fee_payer}\nRecent blockhash: {}\nInstructions:",
+        message.recent_blockhash
+    );
+    for (index, ix) in message.instructions.iter().enumerate() {
+        let program_id = message.account_keys[ix.program_id_index as usize];
+        summary.push_str(&format!(
+            "\n  [{index}] program {program_id} ({} accounts, {} bytes of data)",
+            ix.accounts.len(),
+            ix.data.len()
+        ));
+    }
+    summary
+}
+
+pub fn sign_proposal(proposal: &UnsignedProposal, signer: &Keypair) -> anyhow::Result<Signature> {
+    let message = proposal.message()?;
+    Ok(signer.sign_message(&message.serialize()))
+}
+
+pub fn write_signature_file(
+    path: &Path,
+    signer: &Pubkey,
+    signature: &Signature,
+) -> anyhow::Result<()> {
+    std::fs::write(path, format!("{signer},{signature}"))
+        .with_context(|| format!("failed to write signature to {}", path.display()))
+}
+
+pub fn read_signature_file(path: &Path) -> anyhow::Result<CollectedSignature> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read signature from {}", path.display()))?;
+    let (signer, signature) = contents
+        .trim()
+        .split_once(',')
+        .ok_or_else(|| anyhow!("malformed signature file {}", path.display()))?;
+
+    Ok(CollectedSignature {
+        signer: Pubkey::from_str(signer)?,
+        signature: Signature::from_str(signature)?,
+    })
+}
+
+// Assembles the final `Transaction` by dropping each collected signature into its
+// signer's slot, requiring every required-signer position to be filled and every
+// signature to verify against the proposal message before the transaction is built.
+pub fn assemble_transaction(
+    proposal: &UnsignedProposal,
+    collected: &[CollectedSignature],
+) -> anyhow::Result<Transaction> {
+    let message = proposal.message()?;
+    let message_bytes = message.serialize();
+    let required_signers = &message.account_keys[..message.header.num_required_signatures as usize];
+
+    let mut tx = Transaction::new_unsigned(message);
+
+    for (index, required_signer) in required_signers.iter().enumerate() {
+        let found = collected
+            .iter()
+            .find(|c| &c.signer == required_signer)
+            .ok_or_else(|| anyhow!("missing signature from required signer {required_signer}"))?;
+
+        if !found
+            .signature
+            .verify(required_signer.as_ref(), &message_bytes)
+        {
+            bail!("signature from {required_signer} does not verify against the proposal message");
+        }
+
+        tx.signatures[index] = found.signature;
+    }
+
+    Ok(tx)
+}
+
+pub async fn submit_assembled(ctx: &ScillaContext, tx: &Transaction) -> anyhow::Result<Signature> {
+    let signature = ctx.rpc().send_and_confirm_transaction(tx).await?;
+    Ok(signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_system_interface::instruction::transfer;
+
+    fn sample_proposal(fee_payer: &Pubkey) -> UnsignedProposal {
+        let to = Pubkey::new_unique();
+        let ix = transfer(fee_payer, &to, 1_000);
+        UnsignedProposal::new(&[ix], fee_payer, Hash::new_unique()).unwrap()
+    }
+
+    #[test]
+    fn test_assemble_transaction_rejects_missing_signer() {
+        let payer = Keypair::new();
+        let proposal = sample_proposal(&payer.pubkey());
+
+        let err = assemble_transaction(&proposal, &[]).unwrap_err();
+        assert!(err.to_string().contains("missing signature"));
+    }
+
+    #[test]
+    fn test_assemble_transaction_rejects_bad_signature() {
+        let payer = Keypair::new();
+        let proposal = sample_proposal(&payer.pubkey());
+
+        let other = Keypair::new();
+        let bad_signature = other.sign_message(b"not the proposal message");
+        let collected = [CollectedSignature {
+            signer: payer.pubkey(),
+            signature: bad_signature,
+        }];
+
+        let err = assemble_transaction(&proposal, &collected).unwrap_err();
+        assert!(err.to_string().contains("does not verify"));
+    }
+
+    #[test]
+    fn test_assemble_transaction_accepts_valid_signature() {
+        let payer = Keypair::new();
+        let proposal = sample_proposal(&payer.pubkey());
+
+        let signature = sign_proposal(&proposal, &payer).unwrap();
+        let collected = [CollectedSignature {
+            signer: payer.pubkey(),
+            signature,
+        }];
+
+        let tx = assemble_transaction(&proposal, &collected).unwrap();
+        assert_eq!(tx.signatures[0], signature);
+    }
+}
@@ -0,0 +1,155 @@
+use {
+    crate::{
+        bench::BenchArgs,
+        commands::Command,
+        context::ScillaContext,
+        misc::helpers::{parse_compute_unit_limit, ComputeBudget, PriorityFee, SolAmount},
+    },
+    anyhow::anyhow,
+    std::{str::FromStr, time::Duration},
+};
+
+const COMMANDS: &[&str] = &[
+    "Transfer",
+    "Set compute budget",
+    "Bench",
+    "Propose offline transfer",
+    "Sign offline proposal",
+    "Assemble offline proposal",
+    "Airdrop",
+    "Exit",
+];
+
+pub fn prompt_for_command(ctx: &ScillaContext) -> anyhow::Result<Command> {
+    let selection = ctx.prompt_select("What would you like to do?", COMMANDS)?;
+
+    match COMMANDS[selection] {
+        "Transfer" => prompt_transfer(ctx),
+        "Set compute budget" => prompt_set_compute_budget(ctx).map(Command::SetComputeBudget),
+        "Bench" => prompt_bench(ctx),
+        "Propose offline transfer" => prompt_propose_transfer(ctx),
+        "Sign offline proposal" => prompt_sign_proposal(ctx),
+        "Assemble offline proposal" => prompt_assemble_proposal(ctx),
+        "Airdrop" => prompt_airdrop(ctx),
+        "Exit" => Ok(Command::Exit),
+        other => Err(anyhow!("unknown command: {other}")),
+    }
+}
+
+fn prompt_transfer(ctx: &ScillaContext) -> anyhow::Result<Command> {
+    let to = ctx.read_line("Recipient pubkey")?;
+    let amount_str = ctx.read_line("Amount (SOL)")?;
+    let amount = SolAmount::from_str(&amount_str)?;
+    let lookup_tables_str =
+        ctx.read_line("Address lookup table accounts (comma separated, optional)")?;
+
+    Ok(Command::Transfer {
+        to: solana_pubkey::Pubkey::from_str(&to)?,
+        lamports: amount.to_lamports(),
+        lookup_tables: parse_pubkey_list(&lookup_tables_str)?,
+    })
+}
+
+fn parse_pubkey_list(s: &str) -> anyhow::Result<Vec<solana_pubkey::Pubkey>> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(solana_pubkey::Pubkey::from_str)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Into::into)
+}
+
+// Sets the session-wide compute budget (ScillaContext::set_compute_budget), applied to
+// every transaction the session subsequently builds (transfer, bench, offline proposal).
+fn prompt_set_compute_budget(ctx: &ScillaContext) -> anyhow::Result<ComputeBudget> {
+    let unit_limit_str = ctx.read_line("Compute unit limit (optional)")?;
+    let priority_fee_str = ctx.read_line("Priority fee, micro-lamports per CU (optional)")?;
+
+    let priority_fee = if priority_fee_str.trim().is_empty() {
+        None
+    } else {
+        Some(PriorityFee::from_str(&priority_fee_str)?)
+    };
+
+    Ok(ComputeBudget {
+        unit_limit: parse_compute_unit_limit(&unit_limit_str)?,
+        priority_fee,
+    })
+}
+
+fn prompt_bench(ctx: &ScillaContext) -> anyhow::Result<Command> {
+    let tx_per_run = ctx.read_line("Transactions per run (default 50)")?;
+    let runs = ctx.read_line("Number of runs (default 5)")?;
+    let interval_secs = ctx.read_line("Interval between runs in seconds (default 2)")?;
+    let metrics_file = ctx.read_line("Metrics CSV file (optional)")?;
+
+    Ok(Command::Bench(BenchArgs {
+        tx_per_run: non_empty_or(&tx_per_run, "50").parse()?,
+        runs: non_empty_or(&runs, "5").parse()?,
+        run_interval: Duration::from_secs(non_empty_or(&interval_secs, "2").parse()?),
+        metrics_file: if metrics_file.trim().is_empty() {
+            None
+        } else {
+            Some(metrics_file.trim().into())
+        },
+    }))
+}
+
+fn non_empty_or<'a>(value: &'a str, default: &'a str) -> &'a str {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        default
+    } else {
+        trimmed
+    }
+}
+
+fn prompt_propose_transfer(ctx: &ScillaContext) -> anyhow::Result<Command> {
+    let to = ctx.read_line("Recipient pubkey")?;
+    let amount_str = ctx.read_line("Amount (SOL)")?;
+    let amount = SolAmount::from_str(&amount_str)?;
+    let proposal_out = ctx.read_line("Write unsigned proposal to")?;
+
+    Ok(Command::ProposeTransfer {
+        to: solana_pubkey::Pubkey::from_str(&to)?,
+        lamports: amount.to_lamports(),
+        proposal_out: proposal_out.trim().into(),
+    })
+}
+
+fn prompt_sign_proposal(ctx: &ScillaContext) -> anyhow::Result<Command> {
+    let proposal_path = ctx.read_line("Proposal file to sign")?;
+    let signature_out = ctx.read_line("Write detached signature to")?;
+
+    Ok(Command::SignProposal {
+        proposal_path: proposal_path.trim().into(),
+        signature_out: signature_out.trim().into(),
+    })
+}
+
+fn prompt_assemble_proposal(ctx: &ScillaContext) -> anyhow::Result<Command> {
+    let proposal_path = ctx.read_line("Proposal file")?;
+    let signature_paths = ctx.read_line("Signature files (comma separated)")?;
+
+    Ok(Command::AssembleProposal {
+        proposal_path: proposal_path.trim().into(),
+        signature_paths: signature_paths
+            .split(',')
+            .map(|s| s.trim().into())
+            .collect(),
+    })
+}
+
+fn prompt_airdrop(ctx: &ScillaContext) -> anyhow::Result<Command> {
+    let target = ctx.read_line("Target pubkey (blank for the configured payer)")?;
+    let desired_str = ctx.read_line("Desired balance (SOL)")?;
+
+    Ok(Command::Airdrop {
+        target: if target.trim().is_empty() {
+            None
+        } else {
+            Some(solana_pubkey::Pubkey::from_str(&target)?)
+        },
+        desired: SolAmount::from_str(&desired_str)?,
+    })
+}